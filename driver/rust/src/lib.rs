@@ -5,16 +5,23 @@
 // Query results are returned as a single MessagePack-encoded byte buffer
 // via typedb_transaction_query().
 
+use std::cell::UnsafeCell;
 use std::ffi::{c_char, CStr, CString};
+use std::future::Future;
+use std::pin::Pin;
 use std::ptr::null_mut;
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, OnceLock};
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::{Arc, Condvar, Mutex, OnceLock};
 use std::sync::Once;
-use std::time::Duration;
+use std::task::{Context, Poll, Wake, Waker};
+use std::time::{Duration, Instant};
 
 use tokio::runtime::Runtime;
 use tokio::task::JoinHandle;
 
+use futures::stream::FuturesUnordered;
+use futures::Stream;
+
 use serde_json::json;
 
 use typedb_driver::{
@@ -77,6 +84,17 @@ fn set_error(err_out: *mut *mut c_char, err: impl std::fmt::Display) {
     }
 }
 
+/// Status code an FFI entry point returns when the Rust code underneath it
+/// panicked instead of returning normally.
+const TYPEDB_PANIC: i8 = -1;
+
+/// Run `f`, catching any panic so it cannot unwind across the `extern "C"`
+/// boundary (doing so is undefined behavior). Returns `Err(())` if `f`
+/// panicked; the caller decides how to surface that across the ABI.
+fn catch_ffi<F: FnOnce() -> R, R>(f: F) -> Result<R, ()> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).map_err(|_| ())
+}
+
 // ---------------------------------------------------------------------------
 // String helpers
 // ---------------------------------------------------------------------------
@@ -133,14 +151,155 @@ fn get_runtime() -> Arc<Runtime> {
 // QueryFuture — async wrapper for non-blocking query execution
 // ---------------------------------------------------------------------------
 
+/// Bits of `QueryFuture::state`. `INACTIVE` set means nobody currently owns
+/// `cell`; a poller must atomically clear it before touching the cell and
+/// set it again when it finishes, so at most one thread ever calls
+/// `Pin::poll`/`JoinHandle::abort` on the same handle at a time. `READY`
+/// means a result is sitting in the cell.
+///
+/// This bit only arbitrates access to `cell` — it says nothing about how
+/// long the `QueryFuture` allocation itself stays alive. Losing the race for
+/// it does not mean the winner is about to free the allocation out from
+/// under you: that's handled separately by reference-counting the
+/// allocation (see `clone_handle`), so a thread that fails to acquire `cell`
+/// can safely retry or back off without ever risking a dereference of freed
+/// memory.
+const INACTIVE: u8 = 0b001;
+const READY: u8 = 0b010;
+
+/// What `QueryFuture::cell` currently holds. Guarded entirely by
+/// `QueryFuture::state`: only touched while `INACTIVE` is cleared.
+enum FutureSlot {
+    Pending(JoinHandle<Result<Vec<u8>, String>>),
+    Ready(Result<Vec<u8>, String>),
+    Taken,
+}
+
 #[allow(private_interfaces)]
 pub struct QueryFuture {
-    handle: Option<JoinHandle<Result<Vec<u8>, String>>>,
-    result: Option<Result<Vec<u8>, String>>,
+    state: AtomicU8,
+    cell: UnsafeCell<FutureSlot>,
+    /// Most recently installed poll callback, kept alive so a wake that
+    /// fires after a `MaybeReady` return still has somewhere to call back to.
+    waker: Mutex<Option<Arc<FfiWaker>>>,
     aborted: Arc<AtomicBool>,
+    /// Set by `typedb_future_cancel` to wake a blocked `typedb_future_wait`
+    /// without freeing the future, so the result can still be inspected.
+    cancelled: AtomicBool,
+    notifier: Arc<WaitNotifier>,
     runtime: Arc<Runtime>,
 }
 
+// SAFETY: `cell` is only ever accessed while `INACTIVE` is cleared, and the
+// state machine in `try_acquire`/`release` guarantees at most one thread
+// holds that right at a time.
+unsafe impl Sync for QueryFuture {}
+
+impl QueryFuture {
+    /// Try to become the exclusive owner of `cell`: atomically clear
+    /// `INACTIVE`. Returns true if this call now owns the cell.
+    fn try_acquire(&self) -> bool {
+        self.state.fetch_and(!INACTIVE, Ordering::AcqRel) & INACTIVE != 0
+    }
+
+    /// Release ownership of `cell` by re-setting `INACTIVE`.
+    fn release(&self) {
+        self.state.fetch_or(INACTIVE, Ordering::AcqRel);
+    }
+}
+
+/// Reconstruct an owned `Arc<QueryFuture>` from the raw pointer handed across
+/// the FFI boundary, without consuming the canonical reference created by
+/// `typedb_transaction_query_async` (see the matching `Arc::into_raw` there).
+///
+/// Every function in this file that isn't consuming the future outright
+/// calls this exactly once, up front, and does all its work through the
+/// returned `Arc` rather than ever dereferencing the bare pointer again.
+/// That's what makes it safe to race `typedb_future_drop`/`_abort` (which
+/// reclaim and drop the canonical reference) against `_poll`/`_wait`/
+/// `_cancel` from another thread: `Arc`'s own atomic refcount guarantees the
+/// allocation outlives every clone still in scope, so nobody ever
+/// dereferences freed memory, regardless of which thread currently owns
+/// `cell`.
+fn clone_handle(future: *const QueryFuture) -> Arc<QueryFuture> {
+    unsafe {
+        Arc::increment_strong_count(future);
+        Arc::from_raw(future)
+    }
+}
+
+/// Poll status codes returned by `typedb_future_poll`.
+const TYPEDB_POLL_READY: i8 = 0;
+const TYPEDB_POLL_MAYBE_READY: i8 = 1;
+
+/// A `*const ()` that we assert is safe to hand across the Send boundary:
+/// it is treated as an opaque token and handed back to `callback` verbatim,
+/// never dereferenced on the Rust side.
+struct SendPtr(*const ());
+unsafe impl Send for SendPtr {}
+unsafe impl Sync for SendPtr {}
+
+/// Bridges a `std::task::Waker` wake-up to the C continuation callback
+/// installed by the most recent `typedb_future_poll` call.
+struct FfiWaker {
+    callback: extern "C" fn(*const (), i8),
+    data: SendPtr,
+}
+
+impl Wake for FfiWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        (self.callback)(self.data.0, TYPEDB_POLL_MAYBE_READY);
+    }
+}
+
+/// Status codes returned by `typedb_future_wait`.
+const TYPEDB_WAIT_READY: i8 = 0;
+const TYPEDB_WAIT_TIMED_OUT: i8 = 1;
+const TYPEDB_WAIT_CANCELLED: i8 = 2;
+
+/// Fallback span used to compute `typedb_future_wait`'s deadline when the
+/// caller-supplied `timeout_ms` is large enough that adding it to
+/// `Instant::now()` would overflow. Ten years is effectively "no timeout" for
+/// any real caller while staying far inside the range every platform's
+/// `Instant` can represent, so the fallback itself can never overflow.
+const MAX_WAIT: Duration = Duration::from_secs(60 * 60 * 24 * 365 * 10);
+
+/// Condvar-backed wake target for `typedb_future_wait`: lets a blocked C
+/// thread park instead of busy-looping, the LDK-style "block until notified"
+/// pattern. `woken` is rechecked after every wait to guard against spurious
+/// wakeups rather than trusting the wakeup itself.
+struct WaitNotifier {
+    woken: Mutex<bool>,
+    condvar: Condvar,
+}
+
+impl WaitNotifier {
+    fn new() -> Self {
+        WaitNotifier { woken: Mutex::new(false), condvar: Condvar::new() }
+    }
+
+    fn notify(&self) {
+        *self.woken.lock().unwrap() = true;
+        self.condvar.notify_all();
+    }
+}
+
+struct NotifierWaker(Arc<WaitNotifier>);
+
+impl Wake for NotifierWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.notify();
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Logging
 // ---------------------------------------------------------------------------
@@ -484,28 +643,29 @@ pub extern "C" fn typedb_transaction_query(
     out_len: *mut usize,
     err_out: *mut *mut c_char,
 ) -> *mut u8 {
-    let t = unsafe { &*txn };
-    let opts = if options.is_null() {
-        QueryOptions::new()
-    } else {
-        unsafe { *(&*options) }
-    };
+    let result = catch_ffi(|| {
+        let t = unsafe { &*txn };
+        let opts = if options.is_null() {
+            QueryOptions::new()
+        } else {
+            unsafe { *(&*options) }
+        };
 
-    let promise = t.query_with_options(c_str(query), opts);
-    let answer: QueryAnswer = match promise.resolve() {
-        Ok(a) => a,
-        Err(e) => {
-            set_error(err_out, e);
-            return null_mut();
-        }
-    };
+        let promise = t.query_with_options(c_str(query), opts);
+        let answer: QueryAnswer = promise.resolve().map_err(|e| e.to_string())?;
+        collect_answer_to_msgpack(answer)
+    });
 
-    match collect_answer_to_msgpack(answer) {
-        Ok(bytes) => vec_to_raw(bytes, out_len),
-        Err(e) => {
+    match result {
+        Ok(Ok(bytes)) => vec_to_raw(bytes, out_len),
+        Ok(Err(e)) => {
             set_error(err_out, e);
             null_mut()
         }
+        Err(()) => {
+            set_error(err_out, "internal panic while executing query");
+            null_mut()
+        }
     }
 }
 
@@ -642,53 +802,66 @@ pub extern "C" fn typedb_transaction_query_async(
         return null_mut();
     }
 
-    let query_str = c_str(query).to_owned();
-    let opts = if options.is_null() {
-        QueryOptions::new()
-    } else {
-        unsafe { *(&*options) }
-    };
+    let submitted = catch_ffi(|| {
+        let query_str = c_str(query).to_owned();
+        let opts = if options.is_null() {
+            QueryOptions::new()
+        } else {
+            unsafe { *(&*options) }
+        };
 
-    let aborted = Arc::new(AtomicBool::new(false));
-    let aborted_clone = aborted.clone();
-    let rt = get_runtime();
+        let aborted = Arc::new(AtomicBool::new(false));
+        let aborted_clone = aborted.clone();
+        let rt = get_runtime();
 
-    // SAFETY: We send the raw transaction pointer to the blocking task.
-    // The caller (Go side) guarantees the transaction stays alive until
-    // the future is resolved or aborted.
-    let txn_ptr = txn as usize; // convert to usize for Send
+        // SAFETY: We send the raw transaction pointer to the blocking task.
+        // The caller (Go side) guarantees the transaction stays alive until
+        // the future is resolved or aborted.
+        let txn_ptr = txn as usize; // convert to usize for Send
 
-    let handle = rt.spawn(async move {
-        tokio::task::spawn_blocking(move || {
-            // Check abort before starting
-            if aborted_clone.load(Ordering::Relaxed) {
-                return Err("query aborted".to_string());
-            }
+        let handle = rt.spawn(async move {
+            tokio::task::spawn_blocking(move || {
+                // Check abort before starting
+                if aborted_clone.load(Ordering::Relaxed) {
+                    return Err("query aborted".to_string());
+                }
 
-            let t = unsafe { &*(txn_ptr as *mut Transaction) };
-            let promise = t.query_with_options(&query_str, opts);
-            let answer: QueryAnswer = match promise.resolve() {
-                Ok(a) => a,
-                Err(e) => return Err(e.to_string()),
-            };
+                let t = unsafe { &*(txn_ptr as *mut Transaction) };
+                let promise = t.query_with_options(&query_str, opts);
+                let answer: QueryAnswer = match promise.resolve() {
+                    Ok(a) => a,
+                    Err(e) => return Err(e.to_string()),
+                };
 
-            // Check abort before collecting results
-            if aborted_clone.load(Ordering::Relaxed) {
-                return Err("query aborted".to_string());
-            }
+                // Check abort before collecting results
+                if aborted_clone.load(Ordering::Relaxed) {
+                    return Err("query aborted".to_string());
+                }
 
-            collect_answer_to_msgpack(answer)
-        })
-        .await
-        .unwrap_or_else(|e| Err(format!("task join error: {}", e)))
+                collect_answer_to_msgpack(answer)
+            })
+            .await
+            .unwrap_or_else(|e| Err(format!("task join error: {}", e)))
+        });
+
+        (handle, aborted, rt)
     });
 
-    Box::into_raw(Box::new(QueryFuture {
-        handle: Some(handle),
-        result: None,
-        aborted,
-        runtime: rt,
-    }))
+    match submitted {
+        Ok((handle, aborted, rt)) => Arc::into_raw(Arc::new(QueryFuture {
+            state: AtomicU8::new(INACTIVE),
+            cell: UnsafeCell::new(FutureSlot::Pending(handle)),
+            waker: Mutex::new(None),
+            aborted,
+            cancelled: AtomicBool::new(false),
+            notifier: Arc::new(WaitNotifier::new()),
+            runtime: rt,
+        })) as *mut QueryFuture,
+        Err(()) => {
+            set_error(err_out, "internal panic while submitting query");
+            null_mut()
+        }
+    }
 }
 
 /// Check if a QueryFuture has completed (non-blocking).
@@ -697,13 +870,131 @@ pub extern "C" fn typedb_future_is_ready(future: *const QueryFuture) -> bool {
     if future.is_null() {
         return true;
     }
-    let f = unsafe { &*future };
-    if f.result.is_some() {
+    let f = clone_handle(future);
+    if f.state.load(Ordering::Acquire) & READY != 0 {
         return true;
     }
-    match &f.handle {
-        Some(h) => h.is_finished(),
-        None => true,
+    if !f.try_acquire() {
+        return false;
+    }
+    let finished = match unsafe { &*f.cell.get() } {
+        FutureSlot::Pending(handle) => handle.is_finished(),
+        FutureSlot::Ready(_) | FutureSlot::Taken => true,
+    };
+    f.release();
+    finished
+}
+
+/// Attempt one non-blocking poll of the future, driving it from the caller's
+/// own event loop instead of a dedicated blocking thread. Installs `callback`
+/// as the future's waker: if the poll returns `MaybeReady`, `callback(data, _)`
+/// fires once the future is next woken and the caller should poll again.
+/// `callback`/`data` are re-stored on every call, so a re-wake after a
+/// `MaybeReady` return always re-arms against the most recent pair, and
+/// calling this repeatedly (including spurious re-polls) is safe.
+/// Returns `TYPEDB_POLL_READY` (0) once the result is available — fetch it
+/// with `typedb_future_complete` — or `TYPEDB_POLL_MAYBE_READY` (1) otherwise,
+/// and `TYPEDB_PANIC` (-1) if the poll itself panicked.
+#[no_mangle]
+pub extern "C" fn typedb_future_poll(
+    future: *mut QueryFuture,
+    callback: extern "C" fn(*const (), i8),
+    data: *const (),
+) -> i8 {
+    if future.is_null() {
+        return TYPEDB_POLL_READY;
+    }
+    let f = clone_handle(future);
+
+    if f.state.load(Ordering::Acquire) & READY != 0 {
+        return TYPEDB_POLL_READY;
+    }
+
+    if !f.try_acquire() {
+        // Another poll or a drop currently owns the cell; rather than race
+        // it, report "not ready yet" — the caller's existing wake (or the
+        // drop finishing) will prompt it to try again.
+        return TYPEDB_POLL_MAYBE_READY;
+    }
+
+    let outcome = catch_ffi(|| {
+        let waker_state = Arc::new(FfiWaker { callback, data: SendPtr(data) });
+        *f.waker.lock().unwrap() = Some(waker_state.clone());
+        let waker = Waker::from(waker_state);
+        let mut cx = Context::from_waker(&waker);
+
+        let slot = unsafe { &mut *f.cell.get() };
+        match slot {
+            FutureSlot::Pending(handle) => match Pin::new(handle).poll(&mut cx) {
+                Poll::Ready(res) => {
+                    *slot =
+                        FutureSlot::Ready(res.unwrap_or_else(|e| Err(format!("task join error: {}", e))));
+                    f.state.fetch_or(READY, Ordering::Release);
+                    TYPEDB_POLL_READY
+                }
+                Poll::Pending => TYPEDB_POLL_MAYBE_READY,
+            },
+            FutureSlot::Ready(_) | FutureSlot::Taken => TYPEDB_POLL_READY,
+        }
+    });
+
+    f.release();
+
+    outcome.unwrap_or(TYPEDB_PANIC)
+}
+
+/// Fetch the result of a future that `typedb_future_poll` has reported as
+/// `TYPEDB_POLL_READY`. Returns null and sets `err_out` if the future has not
+/// actually completed yet. Consumes and frees the future.
+/// Caller must free a non-null return with typedb_free_bytes.
+#[no_mangle]
+pub extern "C" fn typedb_future_complete(
+    future: *mut QueryFuture,
+    out_len: *mut usize,
+    err_out: *mut *mut c_char,
+) -> *mut u8 {
+    if future.is_null() {
+        set_error(err_out, "null future pointer");
+        return null_mut();
+    }
+    // Reclaims the canonical reference created at construction — this call
+    // always consumes the future, the same contract as typedb_future_drop.
+    // `f` drops at the end of this function, decrementing that reference;
+    // if a concurrent poll/wait still holds its own clone (see
+    // clone_handle), the allocation survives until it finishes and drops
+    // that clone too.
+    let f = unsafe { Arc::from_raw(future as *const QueryFuture) };
+
+    if !f.try_acquire() {
+        set_error(err_out, "future is being polled or dropped concurrently");
+        return null_mut();
+    }
+
+    let result = catch_ffi(|| std::mem::replace(unsafe { &mut *f.cell.get() }, FutureSlot::Taken));
+
+    match result {
+        Ok(FutureSlot::Ready(Ok(bytes))) => vec_to_raw(bytes, out_len),
+        Ok(FutureSlot::Ready(Err(e))) => {
+            set_error(err_out, e);
+            if !out_len.is_null() {
+                unsafe { *out_len = 0; }
+            }
+            null_mut()
+        }
+        Ok(FutureSlot::Pending(_)) | Ok(FutureSlot::Taken) => {
+            set_error(err_out, "future not ready");
+            if !out_len.is_null() {
+                unsafe { *out_len = 0; }
+            }
+            null_mut()
+        }
+        Err(()) => {
+            set_error(err_out, "internal panic while completing future");
+            if !out_len.is_null() {
+                unsafe { *out_len = 0; }
+            }
+            null_mut()
+        }
     }
 }
 
@@ -721,14 +1012,24 @@ pub extern "C" fn typedb_future_resolve(
         set_error(err_out, "null future pointer");
         return null_mut();
     }
-    let mut f = unsafe { Box::from_raw(future) };
+    // Reclaims the canonical reference created at construction — same
+    // consuming contract as typedb_future_complete/typedb_future_drop.
+    let f = unsafe { Arc::from_raw(future as *const QueryFuture) };
 
-    let result = if let Some(r) = f.result.take() {
-        r
-    } else if let Some(handle) = f.handle.take() {
-        f.runtime.block_on(handle).unwrap_or_else(|e| Err(format!("join error: {}", e)))
-    } else {
-        Err("future already consumed".to_string())
+    if !f.try_acquire() {
+        set_error(err_out, "future is being polled or dropped concurrently");
+        if !out_len.is_null() {
+            unsafe { *out_len = 0; }
+        }
+        return null_mut();
+    }
+
+    let result = match std::mem::replace(unsafe { &mut *f.cell.get() }, FutureSlot::Taken) {
+        FutureSlot::Ready(r) => r,
+        FutureSlot::Pending(handle) => {
+            f.runtime.block_on(handle).unwrap_or_else(|e| Err(format!("join error: {}", e)))
+        }
+        FutureSlot::Taken => Err("future already consumed".to_string()),
     };
 
     match result {
@@ -743,28 +1044,360 @@ pub extern "C" fn typedb_future_resolve(
     }
 }
 
+/// Block the calling thread until the future resolves, `timeout_ms`
+/// elapses, or `typedb_future_cancel` is called, whichever comes first.
+/// Once it owns the future's cell, parks on a condvar armed from the
+/// future's `Waker` rather than busy- or un-cancellably blocking, rechecking
+/// state after every wake to guard against spurious wakeups; while another
+/// thread owns the cell it yields in a loop, still honouring the deadline
+/// and cancellation. Does not consume or free the future — fetch
+/// the result afterwards with `typedb_future_resolve`/`typedb_future_complete`,
+/// or drop it with `typedb_future_drop`.
+///
+/// Safe to call concurrently with `typedb_future_drop`/`_abort`/`_complete`/
+/// `_resolve` from another thread: we hold our own `Arc` clone (see
+/// `clone_handle`) for the whole call, including while parked on the
+/// condvar, so the allocation can't be freed out from under us no matter
+/// what a concurrent consumer does with the canonical reference. The whole
+/// body runs inside `catch_ffi`, and the deadline is computed with a
+/// checked add, so neither a panic nor an overflowing `timeout_ms` can
+/// unwind across the FFI boundary.
+#[no_mangle]
+pub extern "C" fn typedb_future_wait(future: *mut QueryFuture, timeout_ms: u64) -> i8 {
+    if future.is_null() {
+        return TYPEDB_WAIT_READY;
+    }
+
+    catch_ffi(|| {
+        let f = clone_handle(future);
+        let deadline = Instant::now()
+            .checked_add(Duration::from_millis(timeout_ms))
+            .unwrap_or_else(|| Instant::now() + MAX_WAIT);
+
+        // Hold the cell for the whole wait, not just while actually
+        // polling, so nothing else can poll or abort the same handle out
+        // from under us between iterations or while parked below.
+        loop {
+            if f.try_acquire() {
+                break;
+            }
+            if f.cancelled.load(Ordering::Acquire) {
+                return TYPEDB_WAIT_CANCELLED;
+            }
+            if Instant::now() >= deadline {
+                return TYPEDB_WAIT_TIMED_OUT;
+            }
+            std::thread::yield_now();
+        }
+
+        let result = loop {
+            if f.state.load(Ordering::Acquire) & READY != 0 {
+                break TYPEDB_WAIT_READY;
+            }
+            if f.cancelled.load(Ordering::Acquire) {
+                break TYPEDB_WAIT_CANCELLED;
+            }
+
+            let waker = Waker::from(Arc::new(NotifierWaker(f.notifier.clone())));
+            let mut cx = Context::from_waker(&waker);
+
+            let slot = unsafe { &mut *f.cell.get() };
+            let ready = match slot {
+                FutureSlot::Pending(handle) => match Pin::new(handle).poll(&mut cx) {
+                    Poll::Ready(res) => {
+                        *slot = FutureSlot::Ready(
+                            res.unwrap_or_else(|e| Err(format!("task join error: {}", e))),
+                        );
+                        f.state.fetch_or(READY, Ordering::Release);
+                        true
+                    }
+                    Poll::Pending => false,
+                },
+                FutureSlot::Ready(_) | FutureSlot::Taken => true,
+            };
+
+            if ready {
+                break TYPEDB_WAIT_READY;
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                break TYPEDB_WAIT_TIMED_OUT;
+            }
+
+            let mut woken = f.notifier.woken.lock().unwrap();
+            if !*woken {
+                let (guard, _) = f.notifier.condvar.wait_timeout(woken, deadline - now).unwrap();
+                woken = guard;
+            }
+            *woken = false;
+        };
+
+        f.release();
+        result
+    })
+    .unwrap_or(TYPEDB_PANIC)
+}
+
+/// Signal cancellation of a pending future. A thread blocked in
+/// `typedb_future_wait` wakes immediately and returns `TYPEDB_WAIT_CANCELLED`.
+/// Unlike `typedb_future_abort`, this does not free the future or stop the
+/// underlying query — it only unblocks waiters, keeping cancellation
+/// separate from deallocation (which stays the job of `typedb_future_drop`).
+///
+/// Safe to call concurrently with `typedb_future_drop`/`typedb_future_wait`
+/// from another thread: we take our own `Arc` clone up front (see
+/// `clone_handle`) before touching anything else, so the allocation can't be
+/// freed out from under us regardless of whether we also win the race for
+/// `cell` — unlike acquiring `cell`, holding a clone says nothing about who
+/// else may be using the future, only that the memory itself stays valid for
+/// as long as we hold it. Wrapped in `catch_ffi` so an internal panic can't
+/// unwind across the FFI boundary.
+#[no_mangle]
+pub extern "C" fn typedb_future_cancel(future: *mut QueryFuture) {
+    if future.is_null() {
+        return;
+    }
+    let _ = catch_ffi(|| {
+        let f = clone_handle(future);
+        let acquired = f.try_acquire();
+
+        f.cancelled.store(true, Ordering::Release);
+        f.notifier.notify();
+
+        if acquired {
+            f.release();
+        }
+    });
+}
+
+/// Reclaim the canonical `Arc` reference created at construction and, if
+/// nobody else currently owns `cell`, abort the pending task explicitly
+/// instead of merely detaching it — dropping a `JoinHandle` without
+/// aborting it would otherwise let the task keep running and dereference
+/// the caller's `Transaction` pointer after the caller frees it, per
+/// `typedb_transaction_query_async`'s contract that the transaction stays
+/// alive only until the future is resolved or aborted.
+///
+/// If a poll or wait is in flight on another thread right now, we don't
+/// block waiting for it: the `aborted` flag we set beforehand is checked
+/// cooperatively inside the spawned task (see
+/// `typedb_transaction_query_async`), which is what actually guarantees it
+/// stops touching the transaction, regardless of whether we manage to call
+/// `JoinHandle::abort` directly here. Either way, `f` (our `Arc` clone) only
+/// drops at the end of this function, so the allocation survives until
+/// every other clone — including one held by an in-flight poll/wait — is
+/// also dropped.
+fn finalize(future: *mut QueryFuture) {
+    let f = unsafe { Arc::from_raw(future as *const QueryFuture) };
+    if f.try_acquire() {
+        // Dropping the handle runs task teardown glue that could panic;
+        // catch it here so the panic can't cross the FFI boundary. This
+        // function is infallible from the caller's point of view, so we
+        // swallow the panic rather than reporting it.
+        let _ = catch_ffi(std::panic::AssertUnwindSafe(|| {
+            let slot = std::mem::replace(unsafe { &mut *f.cell.get() }, FutureSlot::Taken);
+            if let FutureSlot::Pending(handle) = slot {
+                handle.abort();
+                drop(handle);
+            }
+        }));
+        f.release();
+    }
+}
+
 /// Abort a pending query future. Sets the abort flag and cancels the task.
-/// Frees the future — do not use after calling this.
+/// Frees the future — do not use after calling this. Safe to call
+/// concurrently with `typedb_future_poll`/`typedb_future_wait` from another
+/// thread; see `finalize`.
 #[no_mangle]
 pub extern "C" fn typedb_future_abort(future: *mut QueryFuture) {
     if future.is_null() {
         return;
     }
-    let mut f = unsafe { Box::from_raw(future) };
-    f.aborted.store(true, Ordering::Relaxed);
-    if let Some(handle) = f.handle.take() {
-        handle.abort();
-    }
-    // f is dropped here, freeing all resources
+    clone_handle(future).aborted.store(true, Ordering::Relaxed);
+    finalize(future);
 }
 
-/// Drop an unconsumed future without reading the result.
+/// Drop an unconsumed future without reading the result. Safe to call
+/// concurrently with `typedb_future_poll`/`typedb_future_wait` from another
+/// thread (common when Go moves goroutines across OS threads); see
+/// `finalize`.
 #[no_mangle]
 pub extern "C" fn typedb_future_drop(future: *mut QueryFuture) {
-    if !future.is_null() {
-        let mut f = unsafe { Box::from_raw(future) };
-        if let Some(handle) = f.handle.take() {
-            handle.abort();
+    if future.is_null() {
+        return;
+    }
+    clone_handle(future).aborted.store(true, Ordering::Relaxed);
+    finalize(future);
+}
+
+// ---------------------------------------------------------------------------
+// QueryFutureSet — batch/concurrent query execution
+// ---------------------------------------------------------------------------
+
+/// A member of a `QueryFutureSet`: a query's `JoinHandle` tagged with the
+/// opaque id it was pushed under, so the set can report which query finished.
+struct IdentifiedQuery {
+    id: u64,
+    handle: JoinHandle<Result<Vec<u8>, String>>,
+}
+
+impl Future for IdentifiedQuery {
+    type Output = (u64, Result<Vec<u8>, String>);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        match Pin::new(&mut this.handle).poll(cx) {
+            Poll::Ready(res) => {
+                Poll::Ready((this.id, res.unwrap_or_else(|e| Err(format!("task join error: {}", e)))))
+            }
+            Poll::Pending => Poll::Pending,
         }
     }
 }
+
+/// A set of in-flight queries submitted via `typedb_transaction_query_async`,
+/// driven together so callers get true fan-out concurrency over one
+/// connection instead of spawning a blocking thread per query.
+#[allow(private_interfaces)]
+pub struct QueryFutureSet {
+    futures: FuturesUnordered<IdentifiedQuery>,
+    next_id: u64,
+    #[allow(dead_code)]
+    runtime: Arc<Runtime>,
+}
+
+/// Sentinel id written to `out_id` by `typedb_future_set_poll_next` when no
+/// member query has completed yet; the caller should wait for the re-wake
+/// callback and poll again.
+const TYPEDB_FUTURE_SET_PENDING: u64 = u64::MAX;
+/// Sentinel id written to `out_id` when the set has no queries left to poll.
+const TYPEDB_FUTURE_SET_EMPTY: u64 = u64::MAX - 1;
+
+/// Create an empty `QueryFutureSet`. Caller must free with typedb_future_set_drop.
+#[no_mangle]
+pub extern "C" fn typedb_future_set_new() -> *mut QueryFutureSet {
+    Box::into_raw(Box::new(QueryFutureSet {
+        futures: FuturesUnordered::new(),
+        next_id: 0,
+        runtime: get_runtime(),
+    }))
+}
+
+/// Move a freshly-submitted `QueryFuture` (from `typedb_transaction_query_async`)
+/// into the set and return the opaque id it was assigned. Consumes and frees
+/// `future`. The future must not already have been polled to completion —
+/// returns `TYPEDB_FUTURE_SET_EMPTY` if it has nothing left to poll (or is
+/// owned by a concurrent poll/wait), and `future` is consumed in that case too.
+#[no_mangle]
+pub extern "C" fn typedb_future_set_push(set: *mut QueryFutureSet, future: *mut QueryFuture) -> u64 {
+    if set.is_null() || future.is_null() {
+        return TYPEDB_FUTURE_SET_EMPTY;
+    }
+    let s = unsafe { &mut *set };
+    // Reclaims the canonical reference created at construction — this call
+    // always consumes the future, the same contract as typedb_future_complete.
+    let f = unsafe { Arc::from_raw(future as *const QueryFuture) };
+
+    if !f.try_acquire() {
+        return TYPEDB_FUTURE_SET_EMPTY;
+    }
+
+    let handle = match std::mem::replace(unsafe { &mut *f.cell.get() }, FutureSlot::Taken) {
+        FutureSlot::Pending(handle) => handle,
+        other => {
+            *unsafe { &mut *f.cell.get() } = other;
+            f.release();
+            return TYPEDB_FUTURE_SET_EMPTY;
+        }
+    };
+
+    let id = s.next_id;
+    s.next_id += 1;
+    s.futures.push(IdentifiedQuery { id, handle });
+    id
+}
+
+/// Attempt one non-blocking poll for the next query in the set to complete.
+/// Installs `callback` as the waker for the whole set: if nothing has
+/// completed yet, `callback(data, _)` fires the next time any member query
+/// wakes and the caller should poll again.
+///
+/// On completion, writes the finishing query's id to `out_id` and returns its
+/// result the same way `typedb_transaction_query` does (bytes + out_len, or
+/// null + err_out on a query error). If no query has completed yet, writes
+/// `TYPEDB_FUTURE_SET_PENDING` to `out_id` and returns null. Once the set is
+/// fully drained, writes `TYPEDB_FUTURE_SET_EMPTY` to `out_id` and returns null.
+#[no_mangle]
+pub extern "C" fn typedb_future_set_poll_next(
+    set: *mut QueryFutureSet,
+    callback: extern "C" fn(*const (), i8),
+    data: *const (),
+    out_id: *mut u64,
+    out_len: *mut usize,
+    err_out: *mut *mut c_char,
+) -> *mut u8 {
+    let write_id = |id: u64| {
+        if !out_id.is_null() {
+            unsafe { *out_id = id };
+        }
+    };
+
+    if set.is_null() {
+        write_id(TYPEDB_FUTURE_SET_EMPTY);
+        set_error(err_out, "null future set pointer");
+        return null_mut();
+    }
+
+    let result = catch_ffi(|| {
+        let s = unsafe { &mut *set };
+        let waker = Waker::from(Arc::new(FfiWaker { callback, data: SendPtr(data) }));
+        let mut cx = Context::from_waker(&waker);
+        Pin::new(&mut s.futures).poll_next(&mut cx)
+    });
+
+    match result {
+        Ok(Poll::Ready(Some((id, Ok(bytes))))) => {
+            write_id(id);
+            vec_to_raw(bytes, out_len)
+        }
+        Ok(Poll::Ready(Some((id, Err(e))))) => {
+            write_id(id);
+            set_error(err_out, e);
+            null_mut()
+        }
+        Ok(Poll::Ready(None)) => {
+            write_id(TYPEDB_FUTURE_SET_EMPTY);
+            null_mut()
+        }
+        Ok(Poll::Pending) => {
+            write_id(TYPEDB_FUTURE_SET_PENDING);
+            null_mut()
+        }
+        Err(()) => {
+            write_id(TYPEDB_FUTURE_SET_EMPTY);
+            set_error(err_out, "internal panic while polling future set");
+            null_mut()
+        }
+    }
+}
+
+/// Free a `QueryFutureSet`, aborting and dropping every query still in it.
+/// Draining is panic-safe: if dropping one member query's task handle
+/// panics, the rest are still dropped individually, and the set's own
+/// backing allocation is only released once every member is gone.
+#[no_mangle]
+pub extern "C" fn typedb_future_set_drop(set: *mut QueryFutureSet) {
+    if set.is_null() {
+        return;
+    }
+    let mut s = unsafe { Box::from_raw(set) };
+    let members = std::mem::take(&mut s.futures);
+    for member in members {
+        let _ = catch_ffi(std::panic::AssertUnwindSafe(move || {
+            member.handle.abort();
+            drop(member);
+        }));
+    }
+}